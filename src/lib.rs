@@ -1,7 +1,69 @@
 //! A small library for creating boxed slices `Box<[T]>`.
 
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 use std::mem::MaybeUninit;
 
+#[cfg(feature = "allocator_api")]
+use std::alloc::Allocator;
+
+/// The error returned by the `try_new_*` family of fallible constructors.
+///
+/// This distinguishes a layout computation that overflowed (i.e. `len` is simply too large for
+/// `T`) from the allocator itself failing to satisfy the request.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TryNewError {
+    /// Computing the memory layout for `len` elements overflowed.
+    LayoutOverflow,
+    /// The allocator returned a null pointer.
+    AllocError,
+}
+
+impl std::fmt::Display for TryNewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryNewError::LayoutOverflow => {
+                write!(f, "the requested length overflowed the memory layout")
+            }
+            TryNewError::AllocError => write!(f, "memory allocation failed"),
+        }
+    }
+}
+
+impl std::error::Error for TryNewError {}
+
+/// Drops the already-initialized prefix of a slice if it is itself dropped before being
+/// disarmed with [`mem::forget`](std::mem::forget).
+///
+/// This guards the element-initialization loops in [`new_with`]/[`new_clones`]/[`new_defaults`]
+/// (and their fallible counterparts) against a panicking `gen`, `Clone::clone`, or
+/// `Default::default`: without it, a panic partway through would drop the box as
+/// `MaybeUninit<T>`, which runs no destructors and leaks every `T` already written.
+struct InitGuard<T> {
+    ptr: *mut T,
+    initialized: usize,
+}
+
+impl<T> Drop for InitGuard<T> {
+    fn drop(&mut self) {
+        let initialized_part = std::ptr::slice_from_raw_parts_mut(self.ptr, self.initialized);
+        unsafe { std::ptr::drop_in_place(initialized_part) }
+    }
+}
+
+/// Handles an allocation failure the same way the standard library's infallible constructors do:
+/// a layout overflow panics, and an allocator failure calls [`std::alloc::handle_alloc_error`].
+fn handle_alloc_failure<T>(err: TryNewError, len: usize) -> ! {
+    match err {
+        TryNewError::LayoutOverflow => panic!("capacity overflow"),
+        TryNewError::AllocError => {
+            let layout =
+                std::alloc::Layout::array::<T>(len).expect("capacity overflow already ruled out");
+            std::alloc::handle_alloc_error(layout)
+        }
+    }
+}
+
 /// Assumes all elements of the elements in `ts` are initialized, with the same semantics as
 /// [`MaybeUninit::assume_init`].
 ///
@@ -32,6 +94,58 @@ pub unsafe fn assume_all_init<T>(ts: Box<[MaybeUninit<T>]>) -> Box<[T]> {
     std::mem::transmute(ts)
 }
 
+/// Assumes the elements of `ts` are initialized and returns a shared reference to them, with the
+/// same semantics as [`MaybeUninit::assume_init_ref`].
+///
+/// Unlike [`assume_all_init`], this borrows `ts` instead of consuming it, which lets callers view
+/// an already-initialized prefix of a slice that is still being filled in — useful for
+/// streaming/accumulator patterns where the tail stays uninitialized.
+///
+/// # Safety
+///
+/// The caller must guarantee that every element of `ts` is truly initialized, exactly as for
+/// [`assume_all_init`] and [`MaybeUninit::assume_init_ref`].
+///
+/// # Example
+/// ```
+/// # use boxchop::{assume_init_ref, new_uninit};
+/// #
+/// let mut nums = new_uninit::<usize>(3);
+/// nums[0].write(1);
+/// nums[1].write(2);
+///
+/// // only the first two elements are initialized so far
+/// assert_eq!(unsafe { assume_init_ref(&nums[..2]) }, &[1, 2]);
+/// ```
+pub unsafe fn assume_init_ref<T>(ts: &[MaybeUninit<T>]) -> &[T] {
+    &*(ts as *const [MaybeUninit<T>] as *const [T])
+}
+
+/// Assumes the elements of `ts` are initialized and returns an exclusive reference to them, with
+/// the same semantics as [`MaybeUninit::assume_init_mut`].
+///
+/// # Safety
+///
+/// The caller must guarantee that every element of `ts` is truly initialized, exactly as for
+/// [`assume_all_init`] and [`MaybeUninit::assume_init_mut`].
+///
+/// # Example
+/// ```
+/// # use boxchop::{assume_init_mut, new_uninit};
+/// #
+/// let mut nums = new_uninit::<usize>(3);
+/// nums[0].write(1);
+/// nums[1].write(2);
+///
+/// let initialized = unsafe { assume_init_mut(&mut nums[..2]) };
+/// initialized[0] += 10;
+///
+/// assert_eq!(initialized, &[11, 2]);
+/// ```
+pub unsafe fn assume_init_mut<T>(ts: &mut [MaybeUninit<T>]) -> &mut [T] {
+    &mut *(ts as *mut [MaybeUninit<T>] as *mut [T])
+}
+
 /// Creates a boxed slice of uninitialized memory.
 ///
 /// Use [`MaybeUninit`] to initialize the values and then [`assume_all_init`] to assert all values
@@ -47,21 +161,40 @@ pub unsafe fn assume_all_init<T>(ts: Box<[MaybeUninit<T>]>) -> Box<[T]> {
 /// // all 3 values are uninitialized
 /// ```
 pub fn new_uninit<T>(len: usize) -> Box<[MaybeUninit<T>]> {
+    try_new_uninit(len).unwrap_or_else(|e| handle_alloc_failure::<T>(e, len))
+}
+
+/// Fallible version of [`new_uninit`] that reports layout overflow or allocator failure instead
+/// of panicking or aborting.
+///
+/// # Example
+/// ```
+/// # use boxchop::try_new_uninit;
+/// #
+/// let nothings = try_new_uninit::<usize>(3).unwrap();
+///
+/// assert_eq!(nothings.len(), 3);
+/// ```
+pub fn try_new_uninit<T>(len: usize) -> Result<Box<[MaybeUninit<T>]>, TryNewError> {
     unsafe {
         // Create the slice
         let slice_ref_mut = if std::mem::size_of::<T>() == 0 {
             std::slice::from_raw_parts_mut(std::ptr::NonNull::dangling().as_ptr(), len)
         } else {
             // Allocate the memory for `len` count of `MaybeUninit<T>`s
-            let layout = std::alloc::Layout::array::<MaybeUninit<T>>(len).unwrap();
+            let layout = std::alloc::Layout::array::<MaybeUninit<T>>(len)
+                .map_err(|_| TryNewError::LayoutOverflow)?;
             let mem = std::alloc::alloc(layout) as *mut MaybeUninit<T>;
+            if mem.is_null() {
+                return Err(TryNewError::AllocError);
+            }
 
             // Make slice reference from the pointer of memory
             std::slice::from_raw_parts_mut(mem, len)
         };
 
         // And put it in a box
-        Box::from_raw(slice_ref_mut)
+        Ok(Box::from_raw(slice_ref_mut))
     }
 }
 
@@ -84,24 +217,108 @@ pub fn new_uninit<T>(len: usize) -> Box<[MaybeUninit<T>]> {
 /// );
 /// ```
 pub fn new_zeroed<T>(len: usize) -> Box<[MaybeUninit<T>]> {
+    try_new_zeroed(len).unwrap_or_else(|e| handle_alloc_failure::<T>(e, len))
+}
+
+/// Fallible version of [`new_zeroed`] that reports layout overflow or allocator failure instead
+/// of panicking or aborting.
+///
+/// # Example
+/// ```
+/// # use boxchop::try_new_zeroed;
+/// #
+/// let xs = try_new_zeroed::<usize>(4).unwrap();
+///
+/// assert_eq!(xs.len(), 4);
+/// ```
+pub fn try_new_zeroed<T>(len: usize) -> Result<Box<[MaybeUninit<T>]>, TryNewError> {
     unsafe {
         // Create the slice
         let slice_ref_mut = if std::mem::size_of::<T>() == 0 {
             std::slice::from_raw_parts_mut(std::ptr::NonNull::dangling().as_ptr(), len)
         } else {
             // Allocate the memory for `len` count of `MaybeUninit<T>`s
-            let layout = std::alloc::Layout::array::<MaybeUninit<T>>(len).unwrap();
+            let layout = std::alloc::Layout::array::<MaybeUninit<T>>(len)
+                .map_err(|_| TryNewError::LayoutOverflow)?;
             let mem = std::alloc::alloc_zeroed(layout) as *mut MaybeUninit<T>;
+            if mem.is_null() {
+                return Err(TryNewError::AllocError);
+            }
 
             // Make slice reference from the pointer of memory
             std::slice::from_raw_parts_mut(mem, len)
         };
 
         // And put it in a box
-        Box::from_raw(slice_ref_mut)
+        Ok(Box::from_raw(slice_ref_mut))
     }
 }
 
+/// Marker trait for types whose all-zero bit pattern is a valid value.
+///
+/// # Safety
+///
+/// Implementing this trait asserts that every bit pattern of all zeroes is a valid instance of
+/// `Self`. This holds for the integer/float primitives, `Option<NonZero*>` (zero means `None`),
+/// raw pointers (zero means null), and composites built purely from such types — but it does
+/// *not* hold for e.g. references (the all-zero pattern is a null pointer, which is never a
+/// valid reference) or enums whose zero discriminant isn't one of their variants. Implementing
+/// `Zeroable` for a type where it does not hold is undefined behavior.
+pub unsafe trait Zeroable {}
+
+unsafe impl Zeroable for u8 {}
+unsafe impl Zeroable for u16 {}
+unsafe impl Zeroable for u32 {}
+unsafe impl Zeroable for u64 {}
+unsafe impl Zeroable for u128 {}
+unsafe impl Zeroable for usize {}
+unsafe impl Zeroable for i8 {}
+unsafe impl Zeroable for i16 {}
+unsafe impl Zeroable for i32 {}
+unsafe impl Zeroable for i64 {}
+unsafe impl Zeroable for i128 {}
+unsafe impl Zeroable for isize {}
+unsafe impl Zeroable for f32 {}
+unsafe impl Zeroable for f64 {}
+
+unsafe impl Zeroable for Option<std::num::NonZeroU8> {}
+unsafe impl Zeroable for Option<std::num::NonZeroU16> {}
+unsafe impl Zeroable for Option<std::num::NonZeroU32> {}
+unsafe impl Zeroable for Option<std::num::NonZeroU64> {}
+unsafe impl Zeroable for Option<std::num::NonZeroU128> {}
+unsafe impl Zeroable for Option<std::num::NonZeroUsize> {}
+unsafe impl Zeroable for Option<std::num::NonZeroI8> {}
+unsafe impl Zeroable for Option<std::num::NonZeroI16> {}
+unsafe impl Zeroable for Option<std::num::NonZeroI32> {}
+unsafe impl Zeroable for Option<std::num::NonZeroI64> {}
+unsafe impl Zeroable for Option<std::num::NonZeroI128> {}
+unsafe impl Zeroable for Option<std::num::NonZeroIsize> {}
+
+unsafe impl<T> Zeroable for *const T {}
+unsafe impl<T> Zeroable for *mut T {}
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+
+/// Creates a boxed slice of `len` zeroed elements.
+///
+/// Unlike [`new_zeroed`], this requires no `unsafe` at the call site: the [`Zeroable`] bound
+/// proves the all-zero bit pattern is a valid `T`, so the result is already an initialized
+/// `Box<[T]>`.
+///
+/// # Example
+/// ```
+/// # use boxchop::new_zeroed_init;
+/// #
+/// let xs = new_zeroed_init::<usize>(4);
+///
+/// assert_eq!(xs, Box::from([0, 0, 0, 0]));
+/// ```
+pub fn new_zeroed_init<T: Zeroable>(len: usize) -> Box<[T]> {
+    let ts = new_zeroed(len);
+
+    unsafe { assume_all_init(ts) }
+}
+
 // TODO: new_consts
 
 /// Creates a boxed slice of `len` [copies](Copy) of `val`.
@@ -121,7 +338,28 @@ pub fn new_copies<T>(len: usize, val: T) -> Box<[T]>
 where
     T: Copy,
 {
-    let mut ts = new_uninit(len);
+    try_new_copies(len, val).unwrap_or_else(|e| handle_alloc_failure::<T>(e, len))
+}
+
+/// Fallible version of [`new_copies`] that reports layout overflow or allocator failure instead of
+/// panicking or aborting.
+///
+/// # Example
+/// ```
+/// # use boxchop::try_new_copies;
+/// #
+/// let twelves = try_new_copies(2, 12).unwrap();
+///
+/// assert_eq!(
+///     twelves,
+///     Box::from([12, 12])
+/// );
+/// ```
+pub fn try_new_copies<T>(len: usize, val: T) -> Result<Box<[T]>, TryNewError>
+where
+    T: Copy,
+{
+    let mut ts = try_new_uninit(len)?;
 
     if std::mem::size_of::<T>() != 0 {
         for t in ts.iter_mut() {
@@ -130,7 +368,7 @@ where
         }
     }
 
-    unsafe { assume_all_init(ts) }
+    Ok(unsafe { assume_all_init(ts) })
 }
 
 /// Creates a boxed slice of `len` [clones](Clone) of `val`.
@@ -169,20 +407,83 @@ where
 ///     ])
 /// );
 /// ```
+///
+/// # Panic safety
+///
+/// If `Clone::clone` panics partway through, the elements already cloned are dropped in place by
+/// an internal guard and the original `val` is dropped as the panic unwinds — nothing leaks:
+/// ```
+/// # use boxchop::new_clones;
+/// # use std::panic;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// #
+/// static CLONES: AtomicUsize = AtomicUsize::new(0);
+/// static DROPS: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct PanicsOnThirdClone(u8);
+///
+/// impl Drop for PanicsOnThirdClone {
+///     fn drop(&mut self) {
+///         DROPS.fetch_add(1, Ordering::SeqCst);
+///     }
+/// }
+///
+/// impl Clone for PanicsOnThirdClone {
+///     fn clone(&self) -> Self {
+///         if CLONES.fetch_add(1, Ordering::SeqCst) == 2 {
+///             panic!("boom");
+///         }
+///         PanicsOnThirdClone(self.0)
+///     }
+/// }
+///
+/// let result = panic::catch_unwind(|| new_clones(5, PanicsOnThirdClone(0)));
+/// assert!(result.is_err());
+///
+/// // the 2 clones written before the panic, plus the original `val`, were all dropped
+/// assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+/// ```
 pub fn new_clones<T>(len: usize, val: T) -> Box<[T]>
 where
     T: Clone,
 {
-    let mut ts = new_uninit(len);
+    try_new_clones(len, val).unwrap_or_else(|e| handle_alloc_failure::<T>(e, len))
+}
+
+/// Fallible version of [`new_clones`] that reports layout overflow or allocator failure instead of
+/// panicking or aborting.
+///
+/// # Example
+/// ```
+/// # use boxchop::try_new_clones;
+/// #
+/// let loaf = try_new_clones(2, "wheat").unwrap();
+///
+/// assert_eq!(
+///     loaf,
+///     Box::from(["wheat", "wheat"])
+/// );
+/// ```
+pub fn try_new_clones<T>(len: usize, val: T) -> Result<Box<[T]>, TryNewError>
+where
+    T: Clone,
+{
+    let mut ts = try_new_uninit(len)?;
 
     if std::mem::size_of::<T>() != 0 {
+        let mut guard = InitGuard {
+            ptr: ts.as_mut_ptr() as *mut T,
+            initialized: 0,
+        };
         for t in ts.iter_mut() {
             let ptr: *mut T = t.as_mut_ptr();
             unsafe { ptr.write(val.clone()) }
+            guard.initialized += 1;
         }
+        std::mem::forget(guard);
     }
 
-    unsafe { assume_all_init(ts) }
+    Ok(unsafe { assume_all_init(ts) })
 }
 
 /// Creates a boxed slice of `len` elements using [`Default`].
@@ -205,16 +506,46 @@ pub fn new_defaults<T>(len: usize) -> Box<[T]>
 where
     T: Default,
 {
-    let mut ts = new_uninit(len);
+    try_new_defaults(len).unwrap_or_else(|e| handle_alloc_failure::<T>(e, len))
+}
+
+/// Fallible version of [`new_defaults`] that reports layout overflow or allocator failure instead
+/// of panicking or aborting.
+///
+/// # Example
+/// ```
+/// # use boxchop::try_new_defaults;
+/// #
+/// #[derive(Default, Eq, PartialEq, Debug)]
+/// struct Counter(usize);
+///
+/// let counters = try_new_defaults::<Counter>(2).unwrap();
+///
+/// assert_eq!(
+///     counters,
+///     Box::from([Counter(0), Counter(0)])
+/// );
+/// ```
+pub fn try_new_defaults<T>(len: usize) -> Result<Box<[T]>, TryNewError>
+where
+    T: Default,
+{
+    let mut ts = try_new_uninit(len)?;
 
     if std::mem::size_of::<T>() != 0 {
+        let mut guard = InitGuard {
+            ptr: ts.as_mut_ptr() as *mut T,
+            initialized: 0,
+        };
         for t in ts.iter_mut() {
             let ptr: *mut T = t.as_mut_ptr();
             unsafe { ptr.write(T::default()) }
+            guard.initialized += 1;
         }
+        std::mem::forget(guard);
     }
 
-    unsafe { assume_all_init(ts) }
+    Ok(unsafe { assume_all_init(ts) })
 }
 
 /// Creates a boxed slice of `len` elements using the closure `gen` to generate each element, given
@@ -231,15 +562,329 @@ where
 ///     Box::from([1, 2, 3, 4, 5])
 /// );
 /// ```
-pub fn new_with<T>(len: usize, mut gen: impl FnMut(usize) -> T) -> Box<[T]> {
+pub fn new_with<T>(len: usize, gen: impl FnMut(usize) -> T) -> Box<[T]> {
+    try_new_with(len, gen).unwrap_or_else(|e| handle_alloc_failure::<T>(e, len))
+}
+
+/// Fallible version of [`new_with`] that reports layout overflow or allocator failure instead of
+/// panicking or aborting.
+///
+/// # Example
+/// ```
+/// # use boxchop::try_new_with;
+/// #
+/// let nums = try_new_with(5, |x| x + 1).unwrap();
+///
+/// assert_eq!(
+///     nums,
+///     Box::from([1, 2, 3, 4, 5])
+/// );
+/// ```
+pub fn try_new_with<T>(
+    len: usize,
+    mut gen: impl FnMut(usize) -> T,
+) -> Result<Box<[T]>, TryNewError> {
+    let mut ts = try_new_uninit(len)?;
+
+    if std::mem::size_of::<T>() != 0 {
+        let mut guard = InitGuard {
+            ptr: ts.as_mut_ptr() as *mut T,
+            initialized: 0,
+        };
+        for (idx, t) in ts.iter_mut().enumerate() {
+            let ptr: *mut T = t.as_mut_ptr();
+            unsafe { ptr.write(gen(idx)) }
+            guard.initialized += 1;
+        }
+        std::mem::forget(guard);
+    }
+
+    Ok(unsafe { assume_all_init(ts) })
+}
+
+/// Creates a boxed slice of `len` elements using the fallible closure `gen`, short-circuiting on
+/// the first `Err` it returns.
+///
+/// Elements already written are dropped in place before the error is returned, reusing the same
+/// initialized-prefix guard as [`new_with`]; no partially initialized box ever escapes and nothing
+/// leaks.
+///
+/// # Example
+/// ```
+/// # use boxchop::new_try_with;
+/// #
+/// let nums: Result<_, &str> = new_try_with(5, |x| Ok(x + 1));
+/// assert_eq!(nums, Ok(Box::from([1, 2, 3, 4, 5])));
+///
+/// let err: Result<Box<[usize]>, &str> =
+///     new_try_with(5, |x| if x == 3 { Err("boom") } else { Ok(x) });
+/// assert_eq!(err, Err("boom"));
+/// ```
+///
+/// `gen` is called for every index, including when `T` is a zero-sized type, so an `Err` it
+/// returns is never silently swallowed:
+/// ```
+/// # use boxchop::new_try_with;
+/// #
+/// let mut calls = 0;
+/// let err: Result<Box<[()]>, &str> = new_try_with(5, |x| {
+///     calls += 1;
+///     if x == 3 { Err("boom") } else { Ok(()) }
+/// });
+///
+/// assert_eq!(err, Err("boom"));
+/// assert_eq!(calls, 4);
+/// ```
+pub fn new_try_with<T, E>(
+    len: usize,
+    mut gen: impl FnMut(usize) -> Result<T, E>,
+) -> Result<Box<[T]>, E> {
     let mut ts = new_uninit(len);
 
+    let mut guard = InitGuard {
+        ptr: ts.as_mut_ptr() as *mut T,
+        initialized: 0,
+    };
+    for (idx, t) in ts.iter_mut().enumerate() {
+        let ptr: *mut T = t.as_mut_ptr();
+        let val = gen(idx)?;
+        unsafe { ptr.write(val) }
+        guard.initialized += 1;
+    }
+    std::mem::forget(guard);
+
+    Ok(unsafe { assume_all_init(ts) })
+}
+
+/// Assumes all elements of `ts` are initialized, allocator-aware analogue of [`assume_all_init`]
+/// for boxes built with a custom [`Allocator`].
+#[cfg(feature = "allocator_api")]
+unsafe fn assume_all_init_in<T, A: Allocator>(ts: Box<[MaybeUninit<T>], A>) -> Box<[T], A> {
+    let (ptr, alloc) = Box::into_raw_with_allocator(ts);
+    Box::from_raw_in(ptr as *mut [T], alloc)
+}
+
+/// Creates a boxed slice of uninitialized memory in `alloc`.
+///
+/// Allocator-aware analogue of [`new_uninit`]; requires the (unstable) `allocator_api` feature.
+///
+/// # Example
+/// ```
+/// # #![feature(allocator_api)]
+/// # use boxchop::new_uninit_in;
+/// # use std::alloc::Global;
+/// #
+/// let nothings = new_uninit_in::<usize, _>(3, Global);
+///
+/// assert_eq!(nothings.len(), 3);
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn new_uninit_in<T, A: Allocator>(len: usize, alloc: A) -> Box<[MaybeUninit<T>], A> {
+    unsafe {
+        let slice_ref_mut = if std::mem::size_of::<T>() == 0 {
+            std::slice::from_raw_parts_mut(std::ptr::NonNull::dangling().as_ptr(), len)
+        } else {
+            let layout = std::alloc::Layout::array::<MaybeUninit<T>>(len)
+                .unwrap_or_else(|_| handle_alloc_failure::<T>(TryNewError::LayoutOverflow, len));
+            let mem = alloc
+                .allocate(layout)
+                .unwrap_or_else(|_| handle_alloc_failure::<T>(TryNewError::AllocError, len));
+
+            std::slice::from_raw_parts_mut(mem.as_ptr() as *mut MaybeUninit<T>, len)
+        };
+
+        Box::from_raw_in(slice_ref_mut, alloc)
+    }
+}
+
+/// Creates a boxed slice of zeroed memory in `alloc`.
+///
+/// Allocator-aware analogue of [`new_zeroed`]; requires the (unstable) `allocator_api` feature.
+///
+/// # Example
+/// ```
+/// # #![feature(allocator_api)]
+/// # use boxchop::{assume_all_init, new_zeroed_in};
+/// # use std::alloc::Global;
+/// #
+/// let xs = new_zeroed_in::<usize, _>(4, Global);
+///
+/// assert_eq!(xs.len(), 4);
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn new_zeroed_in<T, A: Allocator>(len: usize, alloc: A) -> Box<[MaybeUninit<T>], A> {
+    unsafe {
+        let slice_ref_mut = if std::mem::size_of::<T>() == 0 {
+            std::slice::from_raw_parts_mut(std::ptr::NonNull::dangling().as_ptr(), len)
+        } else {
+            let layout = std::alloc::Layout::array::<MaybeUninit<T>>(len)
+                .unwrap_or_else(|_| handle_alloc_failure::<T>(TryNewError::LayoutOverflow, len));
+            let mem = alloc
+                .allocate_zeroed(layout)
+                .unwrap_or_else(|_| handle_alloc_failure::<T>(TryNewError::AllocError, len));
+
+            std::slice::from_raw_parts_mut(mem.as_ptr() as *mut MaybeUninit<T>, len)
+        };
+
+        Box::from_raw_in(slice_ref_mut, alloc)
+    }
+}
+
+/// Creates a boxed slice of `len` [copies](Copy) of `val` in `alloc`.
+///
+/// Allocator-aware analogue of [`new_copies`]; requires the (unstable) `allocator_api` feature.
+///
+/// # Example
+/// ```
+/// # #![feature(allocator_api)]
+/// # use boxchop::new_copies_in;
+/// # use std::alloc::Global;
+/// #
+/// let twelves = new_copies_in(2, 12, Global);
+///
+/// assert_eq!(
+///     twelves,
+///     Box::from([12, 12])
+/// );
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn new_copies_in<T, A: Allocator>(len: usize, val: T, alloc: A) -> Box<[T], A>
+where
+    T: Copy,
+{
+    let mut ts = new_uninit_in(len, alloc);
+
     if std::mem::size_of::<T>() != 0 {
+        for t in ts.iter_mut() {
+            let ptr: *mut T = t.as_mut_ptr();
+            unsafe { ptr.write(val) }
+        }
+    }
+
+    unsafe { assume_all_init_in(ts) }
+}
+
+/// Creates a boxed slice of `len` [clones](Clone) of `val` in `alloc`.
+///
+/// Allocator-aware analogue of [`new_clones`]; requires the (unstable) `allocator_api` feature.
+///
+/// # Example
+/// ```
+/// # #![feature(allocator_api)]
+/// # use boxchop::new_clones_in;
+/// # use std::alloc::Global;
+/// #
+/// let loaf = new_clones_in(3, "wheat", Global);
+///
+/// assert_eq!(
+///     loaf,
+///     Box::from(["wheat", "wheat", "wheat"])
+/// );
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn new_clones_in<T, A: Allocator>(len: usize, val: T, alloc: A) -> Box<[T], A>
+where
+    T: Clone,
+{
+    let mut ts = new_uninit_in(len, alloc);
+
+    if std::mem::size_of::<T>() != 0 {
+        let mut guard = InitGuard {
+            ptr: ts.as_mut_ptr() as *mut T,
+            initialized: 0,
+        };
+        for t in ts.iter_mut() {
+            let ptr: *mut T = t.as_mut_ptr();
+            unsafe { ptr.write(val.clone()) }
+            guard.initialized += 1;
+        }
+        std::mem::forget(guard);
+    }
+
+    unsafe { assume_all_init_in(ts) }
+}
+
+/// Creates a boxed slice of `len` elements using [`Default`] in `alloc`.
+///
+/// Allocator-aware analogue of [`new_defaults`]; requires the (unstable) `allocator_api` feature.
+///
+/// # Example
+/// ```
+/// # #![feature(allocator_api)]
+/// # use boxchop::new_defaults_in;
+/// # use std::alloc::Global;
+/// #
+/// #[derive(Default, Eq, PartialEq, Debug)]
+/// struct Counter(usize);
+///
+/// let counters = new_defaults_in::<Counter, _>(2, Global);
+///
+/// assert_eq!(
+///     counters,
+///     Box::from([Counter(0), Counter(0)])
+/// );
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn new_defaults_in<T, A: Allocator>(len: usize, alloc: A) -> Box<[T], A>
+where
+    T: Default,
+{
+    let mut ts = new_uninit_in(len, alloc);
+
+    if std::mem::size_of::<T>() != 0 {
+        let mut guard = InitGuard {
+            ptr: ts.as_mut_ptr() as *mut T,
+            initialized: 0,
+        };
+        for t in ts.iter_mut() {
+            let ptr: *mut T = t.as_mut_ptr();
+            unsafe { ptr.write(T::default()) }
+            guard.initialized += 1;
+        }
+        std::mem::forget(guard);
+    }
+
+    unsafe { assume_all_init_in(ts) }
+}
+
+/// Creates a boxed slice of `len` elements using the closure `gen` in `alloc`, given the
+/// element's index.
+///
+/// Allocator-aware analogue of [`new_with`]; requires the (unstable) `allocator_api` feature.
+///
+/// # Example
+/// ```
+/// # #![feature(allocator_api)]
+/// # use boxchop::new_with_in;
+/// # use std::alloc::Global;
+/// #
+/// let nums = new_with_in(5, |x| x + 1, Global);
+///
+/// assert_eq!(
+///     nums,
+///     Box::from([1, 2, 3, 4, 5])
+/// );
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn new_with_in<T, A: Allocator>(
+    len: usize,
+    mut gen: impl FnMut(usize) -> T,
+    alloc: A,
+) -> Box<[T], A> {
+    let mut ts = new_uninit_in(len, alloc);
+
+    if std::mem::size_of::<T>() != 0 {
+        let mut guard = InitGuard {
+            ptr: ts.as_mut_ptr() as *mut T,
+            initialized: 0,
+        };
         for (idx, t) in ts.iter_mut().enumerate() {
             let ptr: *mut T = t.as_mut_ptr();
             unsafe { ptr.write(gen(idx)) }
+            guard.initialized += 1;
         }
+        std::mem::forget(guard);
     }
 
-    unsafe { assume_all_init(ts) }
+    unsafe { assume_all_init_in(ts) }
 }